@@ -0,0 +1,156 @@
+use std::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::mem::PinMut;
+
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+
+use crate::task::AtomicWaker;
+
+/// Indicates that an `Abortable` future or stream was aborted.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Aborted;
+
+#[derive(Debug)]
+struct AbortInner {
+    waker: AtomicWaker,
+    aborted: AtomicBool,
+}
+
+/// A handle to an `Abortable` future/stream which allows it to be aborted
+/// from elsewhere, e.g. another task.
+#[derive(Debug, Clone)]
+pub struct AbortHandle {
+    inner: Arc<AbortInner>,
+}
+
+/// A registration handle, produced alongside an `AbortHandle`, which is
+/// consumed by `Abortable::new` to link a future/stream to that handle.
+#[derive(Debug)]
+pub struct AbortRegistration {
+    inner: Arc<AbortInner>,
+}
+
+impl AbortHandle {
+    /// Creates an `(AbortHandle, AbortRegistration)` pair which can be used
+    /// to abort a running future or stream.
+    ///
+    /// The `AbortRegistration` is consumed by `Abortable::new`; the
+    /// `AbortHandle` can then be used from anywhere (including another
+    /// thread) to abort that future/stream by calling `abort`.
+    pub fn new_pair() -> (Self, AbortRegistration) {
+        let inner = Arc::new(AbortInner {
+            waker: AtomicWaker::new(),
+            aborted: AtomicBool::new(false),
+        });
+
+        (
+            AbortHandle { inner: inner.clone() },
+            AbortRegistration { inner },
+        )
+    }
+
+    /// Abort the `Abortable` future/stream associated with this handle.
+    ///
+    /// This sets the abort flag and wakes whichever task is currently
+    /// polling the paired `Abortable`, so it promptly resolves to
+    /// `Err(Aborted)` (for a future) or ends (for a stream) without
+    /// polling the wrapped future/stream again.
+    pub fn abort(&self) {
+        self.inner.aborted.store(true, Ordering::Relaxed);
+        self.inner.waker.wake();
+    }
+}
+
+/// A future or stream which can be remotely short-circuited using an
+/// `AbortHandle`.
+#[derive(Debug, Clone)]
+#[must_use = "futures/streams do nothing unless polled"]
+pub struct Abortable<T> {
+    task: T,
+    inner: Arc<AbortInner>,
+}
+
+impl<T> Abortable<T> {
+    unsafe_pinned!(task: T);
+
+    /// Creates a new `Abortable` wrapping `task` (a future or a stream),
+    /// linked to `reg`'s paired `AbortHandle`.
+    pub fn new(task: T, reg: AbortRegistration) -> Self {
+        Abortable { task, inner: reg.inner }
+    }
+
+    // Shared poll logic for both the `Future` and `Stream` impls below.
+    // `Internal` is the inner `poll`/`poll_next`'s output; `None` here means
+    // "aborted", to be distinguished from the inner object's own output by
+    // each trait impl.
+    fn try_poll<Internal>(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+        poll: impl FnOnce(PinMut<T>, &mut task::Context) -> Poll<Internal>,
+    ) -> Poll<Option<Internal>> {
+        // Check before registering a waker, so an abort that already
+        // happened doesn't require an extra wakeup round-trip.
+        if self.inner.aborted.load(Ordering::Relaxed) {
+            return Poll::Ready(None);
+        }
+
+        let waker = cx.waker().clone();
+        self.inner.waker.register(&waker);
+
+        // Re-check after registering: `abort` sets the flag and then wakes,
+        // so an `abort` that set the flag after our first check above is
+        // seen here, and if the flag is still clear here, any racing
+        // `abort`'s wake is guaranteed to reach the waker registered above.
+        if self.inner.aborted.load(Ordering::Relaxed) {
+            return Poll::Ready(None);
+        }
+
+        poll(self.task(), cx).map(Some)
+    }
+}
+
+impl<Fut> Future for Abortable<Fut>
+where
+    Fut: Future,
+{
+    type Output = Result<Fut::Output, Aborted>;
+
+    fn poll(self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        self.try_poll(cx, Future::poll)
+            .map(|output| output.ok_or(Aborted))
+    }
+}
+
+impl<St> Stream for Abortable<St>
+where
+    St: Stream,
+{
+    type Item = St::Item;
+
+    fn poll_next(self: PinMut<Self>, cx: &mut task::Context) -> Poll<Option<Self::Item>> {
+        self.try_poll(cx, Stream::poll_next)
+            .map(|item| item.and_then(|item| item))
+    }
+}
+
+/// Creates a new `Abortable` future and an `AbortHandle` which can be used
+/// to stop it.
+pub fn abortable<Fut>(future: Fut) -> (Abortable<Fut>, AbortHandle)
+where
+    Fut: Future,
+{
+    let (handle, reg) = AbortHandle::new_pair();
+    (Abortable::new(future, reg), handle)
+}
+
+/// Creates a new `Abortable` stream and an `AbortHandle` which can be used
+/// to stop it.
+pub fn abortable_stream<St>(stream: St) -> (Abortable<St>, AbortHandle)
+where
+    St: Stream,
+{
+    let (handle, reg) = AbortHandle::new_pair();
+    (Abortable::new(stream, reg), handle)
+}
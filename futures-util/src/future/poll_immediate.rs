@@ -0,0 +1,36 @@
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::future::Future;
+use futures_core::task::{self, Poll};
+
+/// A future which polls the wrapped future exactly once and yields the
+/// raw `Poll` as its output instead of parking when the future is not
+/// ready.
+///
+/// This future is created by the `FutureExt::poll_immediate` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct PollImmediate<Fut> {
+    future: Fut,
+}
+
+impl<Fut: Unpin> Unpin for PollImmediate<Fut> {}
+
+impl<Fut> PollImmediate<Fut> {
+    unsafe_pinned!(future: Fut);
+
+    pub(super) fn new(future: Fut) -> Self {
+        PollImmediate { future }
+    }
+}
+
+impl<Fut: Future> Future for PollImmediate<Fut> {
+    type Output = Poll<Fut::Output>;
+
+    fn poll(mut self: PinMut<Self>, cx: &mut task::Context) -> Poll<Self::Output> {
+        match self.future().poll(cx) {
+            Poll::Ready(item) => Poll::Ready(Poll::Ready(item)),
+            Poll::Pending => Poll::Ready(Poll::Pending),
+        }
+    }
+}
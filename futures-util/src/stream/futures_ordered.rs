@@ -0,0 +1,186 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::iter::FromIterator;
+use std::prelude::v1::*;
+
+use core::marker::Unpin;
+use core::mem::PinMut;
+
+use futures_core::future::Future;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+
+// A future/its output, tagged with the index at which it should be
+// yielded. `Ord` is implemented purely in terms of `index`, reversed so
+// that `BinaryHeap` (a max-heap) pops the *smallest* index first, i.e.
+// whichever is next due to be released.
+#[derive(Debug)]
+struct Indexed<T> {
+    index: isize,
+    data: T,
+}
+
+impl<T> PartialEq for Indexed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for Indexed<T> {}
+
+impl<T> PartialOrd for Indexed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Indexed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.index.cmp(&self.index)
+    }
+}
+
+/// An unbounded queue of futures which yields completions in the order the
+/// futures were submitted, rather than the order in which they complete.
+///
+/// Futures are assigned an index as they're added with `push_back` (or
+/// prepended with `push_front`), and a completed future's output is held
+/// back behind any still-pending future with a lower index, so the stream
+/// always yields outputs in index order.
+///
+/// Each call to `poll_next` re-polls every future still in `in_progress` in
+/// turn (an O(n) scan), rather than waking only the individual futures that
+/// have new notifications; this is simpler than a notification-driven ready
+/// queue at the cost of that per-poll scan.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct FuturesOrdered<Fut>
+where
+    Fut: Future,
+{
+    in_progress: Vec<Indexed<Fut>>,
+    queued_outputs: BinaryHeap<Indexed<Fut::Output>>,
+    next_incoming_index: isize,
+    next_outgoing_index: isize,
+}
+
+impl<Fut: Future> Unpin for FuturesOrdered<Fut> {}
+
+impl<Fut: Future> FuturesOrdered<Fut> {
+    /// Creates a new, empty `FuturesOrdered`.
+    pub fn new() -> Self {
+        FuturesOrdered {
+            in_progress: Vec::new(),
+            queued_outputs: BinaryHeap::new(),
+            next_incoming_index: 0,
+            next_outgoing_index: 0,
+        }
+    }
+
+    /// Returns the number of futures contained in the queue.
+    ///
+    /// This includes both futures still being polled and ones that have
+    /// already resolved but are being held back behind an earlier,
+    /// still-pending future.
+    pub fn len(&self) -> usize {
+        self.in_progress.len() + self.queued_outputs.len()
+    }
+
+    /// Returns `true` if the queue contains no futures.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pushes a future into the queue, to be yielded after every future
+    /// already in the queue.
+    pub fn push_back(&mut self, future: Fut) {
+        let index = self.next_incoming_index;
+        self.next_incoming_index += 1;
+        self.in_progress.push(Indexed { index, data: future });
+    }
+
+    /// Pushes a future into the queue, to be yielded before every future
+    /// already in the queue.
+    ///
+    /// Only futures already queued at the time of the call are guaranteed
+    /// to come after it; a later `push_front` takes precedence over an
+    /// earlier one, the same way prepending to any other deque does.
+    pub fn push_front(&mut self, future: Fut) {
+        self.next_outgoing_index -= 1;
+        let index = self.next_outgoing_index;
+        self.in_progress.push(Indexed { index, data: future });
+    }
+
+    /// Pushes a future into the queue, to be yielded after every future
+    /// already in the queue.
+    ///
+    /// This is an alias for `push_back`.
+    pub fn push(&mut self, future: Fut) {
+        self.push_back(future);
+    }
+}
+
+impl<Fut: Future> Default for FuturesOrdered<Fut> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Fut: Future + Unpin> Stream for FuturesOrdered<Fut> {
+    type Item = Fut::Output;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        let this = PinMut::get_mut(&mut self);
+
+        let mut i = 0;
+        while i < this.in_progress.len() {
+            match PinMut::new(&mut this.in_progress[i].data).poll(cx) {
+                Poll::Ready(output) => {
+                    let Indexed { index, .. } = this.in_progress.remove(i);
+                    this.queued_outputs.push(Indexed { index, data: output });
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+
+        if let Some(&Indexed { index, .. }) = this.queued_outputs.peek() {
+            if index == this.next_outgoing_index {
+                this.next_outgoing_index += 1;
+                return Poll::Ready(Some(this.queued_outputs.pop().unwrap().data));
+            }
+        }
+
+        if this.in_progress.is_empty() && this.queued_outputs.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut: Future> FromIterator<Fut> for FuturesOrdered<Fut> {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Fut>,
+    {
+        let mut queue = FuturesOrdered::new();
+        for future in iter {
+            queue.push_back(future);
+        }
+        queue
+    }
+}
+
+/// Converts a list of futures into a `Stream` of results from the futures,
+/// yielded in the order of the list of futures (as opposed to the order of
+/// completion).
+pub fn futures_ordered<I>(futures: I) -> FuturesOrdered<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    futures.into_iter().collect()
+}
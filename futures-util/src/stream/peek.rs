@@ -48,6 +48,63 @@ impl<St: Stream> Peekable<St> {
             }
         }
     }
+
+    /// Peek retrieves a mutable reference to the next item in the stream.
+    ///
+    /// This method behaves exactly like the `peek` method except it returns
+    /// a mutable reference instead.
+    pub fn poll_peek_mut<'a>(
+        self: &'a mut PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<&'a mut St::Item>> {
+        if self.peeked().is_some() {
+            return Poll::Ready(self.peeked().as_mut())
+        }
+        match ready!(self.stream().poll_next(cx)) {
+            None => Poll::Ready(None),
+            Some(item) => {
+                *self.peeked() = Some(item);
+                Poll::Ready(self.peeked().as_mut())
+            }
+        }
+    }
+
+    /// Consume and return the next item in the stream if `func` returns
+    /// `true` for a peek of the next item.
+    ///
+    /// The predicate is evaluated against the next item in the stream
+    /// without consuming it, so if it returns `false` the item remains
+    /// buffered and will still be returned by the subsequent `poll_next`.
+    pub fn poll_next_if<F>(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+        func: F,
+    ) -> Poll<Option<St::Item>>
+    where
+        F: FnOnce(&St::Item) -> bool,
+    {
+        match ready!((&mut self).peek(cx)) {
+            Some(item) if func(item) => {}
+            _ => return Poll::Ready(None),
+        }
+        Poll::Ready(self.peeked().take())
+    }
+
+    /// Consume and return the next item in the stream if it is equal to
+    /// `expected`.
+    ///
+    /// Like `poll_next_if`, a non-matching item is left buffered so it is
+    /// still returned in order by a later `poll_next`.
+    pub fn poll_next_if_eq<T: ?Sized>(
+        self: PinMut<Self>,
+        cx: &mut task::Context,
+        expected: &T,
+    ) -> Poll<Option<St::Item>>
+    where
+        St::Item: PartialEq<T>,
+    {
+        self.poll_next_if(cx, |item| item == expected)
+    }
 }
 
 impl<S: Stream> Stream for Peekable<S> {
@@ -0,0 +1,41 @@
+use crate::stream::{StreamExt, Fuse};
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+
+/// A stream which polls the wrapped stream exactly once per item and
+/// yields the raw `Poll` rather than parking when the stream is not
+/// ready.
+///
+/// This stream is created by the `StreamExt::poll_immediate` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct PollImmediate<St: Stream> {
+    stream: Fuse<St>,
+}
+
+impl<St: Stream + Unpin> Unpin for PollImmediate<St> {}
+
+impl<St: Stream> PollImmediate<St> {
+    unsafe_pinned!(stream: Fuse<St>);
+
+    pub(super) fn new(stream: St) -> Self {
+        PollImmediate { stream: stream.fuse() }
+    }
+}
+
+impl<St: Stream> Stream for PollImmediate<St> {
+    type Item = Poll<St::Item>;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        match self.stream().poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(Poll::Ready(item))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Ready(Some(Poll::Pending)),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::stream::{Fuse, StreamExt};
+use core::marker::Unpin;
+use core::mem::PinMut;
+use futures_core::stream::Stream;
+use futures_core::task::{self, Poll};
+
+/// Tells `SelectWithStrategy` which stream to poll next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollNext {
+    /// Poll the first stream.
+    Left,
+    /// Poll the second stream.
+    Right,
+}
+
+impl PollNext {
+    /// Toggle the value and return the old one.
+    pub fn toggle(&mut self) -> Self {
+        let old = *self;
+        *self = self.other();
+        old
+    }
+
+    fn other(&self) -> Self {
+        match self {
+            PollNext::Left => PollNext::Right,
+            PollNext::Right => PollNext::Left,
+        }
+    }
+}
+
+impl Default for PollNext {
+    fn default() -> Self {
+        PollNext::Left
+    }
+}
+
+/// Stream for the `select_with_strategy` function. See function docs for
+/// more details.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct SelectWithStrategy<St1: Stream, St2: Stream, Clos, State> {
+    stream1: Fuse<St1>,
+    stream2: Fuse<St2>,
+    state: State,
+    clos: Clos,
+}
+
+impl<St1: Stream + Unpin, St2: Stream + Unpin, Clos, State> Unpin
+    for SelectWithStrategy<St1, St2, Clos, State> {}
+
+/// This function will attempt to pull items from both input streams. Unlike
+/// `select`, which interleaves the two streams round-robin, the caller picks
+/// which stream is favored on each `poll_next` via `clos`, which is called
+/// with a user-supplied `state` and returns a `PollNext` saying which side
+/// to poll first. Whichever side isn't favored is polled only if the
+/// favored side was `Pending` or exhausted. This is useful for e.g. always
+/// draining a priority/control stream ahead of a bulk data stream.
+///
+/// Note that this function consumes both streams and returns a wrapped
+/// version of them.
+pub fn select_with_strategy<St1, St2, Clos, State>(
+    stream1: St1,
+    stream2: St2,
+    state: State,
+    clos: Clos,
+) -> SelectWithStrategy<St1, St2, Clos, State>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+    Clos: FnMut(&mut State) -> PollNext,
+{
+    SelectWithStrategy { stream1: stream1.fuse(), stream2: stream2.fuse(), state, clos }
+}
+
+impl<St1: Stream, St2: Stream, Clos, State> SelectWithStrategy<St1, St2, Clos, State> {
+    unsafe_pinned!(stream1: Fuse<St1>);
+    unsafe_pinned!(stream2: Fuse<St2>);
+
+    // `state` and `clos` are not structurally pinned: project both at once
+    // so the closure can be invoked with a live `&mut State` without the
+    // borrow checker seeing two overlapping borrows of `self`.
+    fn clos_and_state<'a>(self: &'a mut PinMut<Self>) -> (&'a mut Clos, &'a mut State) {
+        unsafe {
+            let this = PinMut::get_mut_unchecked(self.reborrow());
+            (&mut this.clos, &mut this.state)
+        }
+    }
+}
+
+impl<St1, St2, Clos, State> Stream for SelectWithStrategy<St1, St2, Clos, State>
+where
+    St1: Stream,
+    St2: Stream<Item = St1::Item>,
+    Clos: FnMut(&mut State) -> PollNext,
+{
+    type Item = St1::Item;
+
+    fn poll_next(
+        mut self: PinMut<Self>,
+        cx: &mut task::Context,
+    ) -> Poll<Option<Self::Item>> {
+        let (clos, state) = self.clos_and_state();
+        let first = clos(state);
+
+        let (a, b) = match first {
+            PollNext::Left => (self.stream1().poll_next(cx), PollNext::Right),
+            PollNext::Right => (self.stream2().poll_next(cx), PollNext::Left),
+        };
+        let a_done = a.is_ready();
+        if let Poll::Ready(Some(item)) = a {
+            return Poll::Ready(Some(item));
+        }
+
+        let b_poll = match b {
+            PollNext::Left => self.stream1().poll_next(cx),
+            PollNext::Right => self.stream2().poll_next(cx),
+        };
+        let b_done = b_poll.is_ready();
+        if let Poll::Ready(Some(item)) = b_poll {
+            return Poll::Ready(Some(item));
+        }
+
+        if a_done && b_done {
+            // Both streams are exhausted (`Ready(None)`).
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering::{Acquire, AcqRel, Release};
+
+use futures_core::task::Waker;
+
+const WAITING: usize = 0;
+const REGISTERING: usize = 0b01;
+const WAKING: usize = 0b10;
+
+/// A synchronization primitive for task wakeup.
+///
+/// Often futures are implemented using some form of atomic flag alongside
+/// a registered `Waker` that must be notified once the flag changes.
+/// `AtomicWaker` coordinates storing that `Waker` and waking it, so that a
+/// `wake` racing with a `register` can never be lost, which a naive
+/// `Mutex<Option<Waker>>` cannot guarantee without extra locking.
+pub struct AtomicWaker {
+    state: AtomicUsize,
+    waker: UnsafeCell<Option<Waker>>,
+}
+
+unsafe impl Send for AtomicWaker {}
+unsafe impl Sync for AtomicWaker {}
+
+impl AtomicWaker {
+    /// Create a new, empty `AtomicWaker`.
+    pub fn new() -> Self {
+        AtomicWaker {
+            state: AtomicUsize::new(WAITING),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    /// Registers the waker to be notified on calls to `wake`.
+    ///
+    /// The new waker takes the place of any previous waker that was
+    /// registered by a previous call to `register`. Any call to `wake` that
+    /// happens after this call to `register` is guaranteed to wake the
+    /// `Waker` passed in here, even if it races with the registration.
+    pub fn register(&self, waker: &Waker) {
+        match self.state.compare_and_swap(WAITING, REGISTERING, Acquire) {
+            WAITING => {
+                unsafe {
+                    *self.waker.get() = Some(waker.clone());
+
+                    let res = self.state.compare_exchange(
+                        REGISTERING, WAITING, AcqRel, Acquire);
+
+                    if res.is_err() {
+                        // Someone else called `wake` while we were storing
+                        // the waker above; take it back out and wake it
+                        // ourselves so the notification isn't lost.
+                        let waker = (*self.waker.get()).take().unwrap();
+                        self.state.swap(WAITING, AcqRel);
+                        waker.wake();
+                    }
+                }
+            }
+            WAKING => {
+                // A `wake` is in progress. Wake the passed-in waker
+                // directly to avoid missing the notification.
+                waker.wake();
+            }
+            state => {
+                debug_assert!(
+                    state == REGISTERING || state == REGISTERING | WAKING
+                );
+            }
+        }
+    }
+
+    /// Calls `wake` on the last `Waker` passed to `register`.
+    ///
+    /// If `register` has not been called yet, this does nothing.
+    pub fn wake(&self) {
+        if let Some(waker) = self.take() {
+            waker.wake();
+        }
+    }
+
+    fn take(&self) -> Option<Waker> {
+        match self.state.fetch_or(WAKING, AcqRel) {
+            WAITING => {
+                let waker = unsafe { (*self.waker.get()).take() };
+                self.state.fetch_and(!WAKING, Release);
+                waker
+            }
+            state => {
+                debug_assert!(
+                    state == REGISTERING ||
+                    state == REGISTERING | WAKING ||
+                    state == WAKING
+                );
+                None
+            }
+        }
+    }
+}
+
+impl Default for AtomicWaker {
+    fn default() -> Self {
+        AtomicWaker::new()
+    }
+}
+
+impl fmt::Debug for AtomicWaker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AtomicWaker")
+    }
+}
@@ -0,0 +1,4 @@
+//! Task related utilities not provided by `futures-core`.
+
+mod atomic_waker;
+pub use self::atomic_waker::AtomicWaker;
@@ -0,0 +1,83 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+#[macro_use]
+extern crate futures;
+
+use std::mem::PinMut;
+
+use futures::channel::oneshot;
+use futures::future::{abortable, Aborted};
+use futures::prelude::*;
+use futures::stream::abortable_stream;
+use futures::task::{self, Poll};
+
+mod support;
+
+#[test]
+fn abort_before_first_poll() {
+    let (_tx, rx) = oneshot::channel::<i32>();
+    let (mut future, handle) = abortable(rx);
+    handle.abort();
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(future.poll_unpin(cx), Poll::Ready(Err(Aborted)));
+    });
+}
+
+#[test]
+fn abort_after_pending_poll() {
+    let (_tx, rx) = oneshot::channel::<i32>();
+    let (mut future, handle) = abortable(rx);
+
+    support::with_noop_waker_context(|cx| {
+        // Nothing has been sent yet, so the inner future is still pending.
+        assert!(future.poll_unpin(cx).is_pending());
+
+        // This is sequential (poll, then abort, then poll again), not a
+        // concurrent race — it only checks that an abort seen *after* a
+        // waker has been registered by a prior pending poll still takes
+        // effect on the next poll, rather than being missed entirely.
+        handle.abort();
+
+        assert_eq!(future.poll_unpin(cx), Poll::Ready(Err(Aborted)));
+    });
+}
+
+#[test]
+fn abort_is_idempotent() {
+    let (_tx, rx) = oneshot::channel::<i32>();
+    let (mut future, handle) = abortable(rx);
+    handle.abort();
+    handle.abort();
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(future.poll_unpin(cx), Poll::Ready(Err(Aborted)));
+    });
+}
+
+// A stream that is always immediately ready with the next integer, used to
+// exercise `Abortable`'s stream impl without depending on any other
+// unfinished part of the crate.
+struct Counter(i32);
+
+impl Stream for Counter {
+    type Item = i32;
+
+    fn poll_next(mut self: PinMut<Self>, _cx: &mut task::Context) -> Poll<Option<i32>> {
+        self.0 += 1;
+        Poll::Ready(Some(self.0))
+    }
+}
+
+#[test]
+fn abort_stream_ends_early() {
+    let (mut stream, handle) = abortable_stream(Counter(0));
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+
+        handle.abort();
+
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    });
+}
@@ -66,6 +66,50 @@ fn from_iterator() {
     assert_eq!(block_on(stream.collect::<Vec<_>>()), vec![1,2,3]);
 }
 
+#[test]
+fn push_front_reorders_ahead_of_queue() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+
+    let mut stream = futures_ordered(vec![a_rx, b_rx]);
+    stream.push_front(c_rx);
+
+    a_tx.send(1).unwrap();
+    b_tx.send(2).unwrap();
+    c_tx.send(3).unwrap();
+
+    let mut iter = block_on_stream(stream);
+    // `c_rx` was pushed to the front, so it is yielded before the
+    // previously-queued `a_rx`/`b_rx`, even though all three are ready.
+    assert_eq!(Some(Ok(3)), iter.next());
+    assert_eq!(Some(Ok(1)), iter.next());
+    assert_eq!(Some(Ok(2)), iter.next());
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn push_back_is_fifo_with_push_front() {
+    let (a_tx, a_rx) = oneshot::channel::<i32>();
+    let (b_tx, b_rx) = oneshot::channel::<i32>();
+    let (c_tx, c_rx) = oneshot::channel::<i32>();
+
+    let mut stream = FuturesOrdered::new();
+    stream.push_back(a_rx);
+    stream.push_front(b_rx);
+    stream.push_back(c_rx);
+
+    a_tx.send(1).unwrap();
+    b_tx.send(2).unwrap();
+    c_tx.send(3).unwrap();
+
+    let mut iter = block_on_stream(stream);
+    assert_eq!(Some(Ok(2)), iter.next());
+    assert_eq!(Some(Ok(1)), iter.next());
+    assert_eq!(Some(Ok(3)), iter.next());
+    assert_eq!(None, iter.next());
+}
+
 /* ToDo: This requires FutureExt::select to be implemented
 #[test]
 fn queue_never_unblocked() {
@@ -0,0 +1,105 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+#[macro_use]
+extern crate futures;
+
+use std::collections::VecDeque;
+use std::mem::PinMut;
+
+use futures::prelude::*;
+use futures::task::{self, Poll};
+
+mod support;
+
+// A stream that's immediately `Ready` with each of `items` in turn, then
+// `Ready(None)` forever after.
+struct Seq(VecDeque<i32>);
+
+impl Seq {
+    fn new(items: Vec<i32>) -> Self {
+        Seq(items.into_iter().collect())
+    }
+}
+
+impl Stream for Seq {
+    type Item = i32;
+
+    fn poll_next(mut self: PinMut<Self>, _cx: &mut task::Context) -> Poll<Option<i32>> {
+        Poll::Ready(self.0.pop_front())
+    }
+}
+
+#[test]
+fn next_if_false_predicate_leaves_item_buffered() {
+    let mut peekable = Seq::new(vec![1, 2]).peekable();
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(
+            PinMut::new(&mut peekable).poll_next_if(cx, |&x| x == 2),
+            Poll::Ready(None),
+        );
+        // The rejected item must still come back in order.
+        assert_eq!(PinMut::new(&mut peekable).poll_next(cx), Poll::Ready(Some(1)));
+        assert_eq!(PinMut::new(&mut peekable).poll_next(cx), Poll::Ready(Some(2)));
+        assert_eq!(PinMut::new(&mut peekable).poll_next(cx), Poll::Ready(None));
+    });
+}
+
+#[test]
+fn next_if_true_predicate_consumes_item() {
+    let mut peekable = Seq::new(vec![1, 2]).peekable();
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(
+            PinMut::new(&mut peekable).poll_next_if(cx, |&x| x == 1),
+            Poll::Ready(Some(1)),
+        );
+        assert_eq!(PinMut::new(&mut peekable).poll_next(cx), Poll::Ready(Some(2)));
+        assert_eq!(PinMut::new(&mut peekable).poll_next(cx), Poll::Ready(None));
+    });
+}
+
+#[test]
+fn next_if_eq() {
+    let mut peekable = Seq::new(vec![1, 2]).peekable();
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(
+            PinMut::new(&mut peekable).poll_next_if_eq(cx, &2),
+            Poll::Ready(None),
+        );
+        assert_eq!(
+            PinMut::new(&mut peekable).poll_next_if_eq(cx, &1),
+            Poll::Ready(Some(1)),
+        );
+        assert_eq!(PinMut::new(&mut peekable).poll_next(cx), Poll::Ready(Some(2)));
+    });
+}
+
+#[test]
+fn next_if_on_exhausted_stream() {
+    let mut peekable = Seq::new(vec![]).peekable();
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(
+            PinMut::new(&mut peekable).poll_next_if(cx, |_| true),
+            Poll::Ready(None),
+        );
+        assert_eq!(PinMut::new(&mut peekable).poll_next(cx), Poll::Ready(None));
+    });
+}
+
+#[test]
+fn poll_peek_mut_allows_mutation_seen_by_poll_next() {
+    let mut peekable = Seq::new(vec![1]).peekable();
+
+    support::with_noop_waker_context(|cx| {
+        let mut pinned = PinMut::new(&mut peekable);
+        if let Poll::Ready(Some(item)) = (&mut pinned).poll_peek_mut(cx) {
+            *item = 42;
+        } else {
+            panic!("expected a peeked item");
+        }
+        assert_eq!(PinMut::new(&mut peekable).poll_next(cx), Poll::Ready(Some(42)));
+    });
+}
@@ -0,0 +1,76 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+#[macro_use]
+extern crate futures;
+
+use std::mem::PinMut;
+
+use futures::future;
+use futures::prelude::*;
+use futures::task::{self, Poll};
+
+mod support;
+
+// A stream that's `Pending` on its first poll, then yields `item` once and
+// ends.
+struct PendingThenReady {
+    polled: bool,
+    item: Option<i32>,
+}
+
+impl Stream for PendingThenReady {
+    type Item = i32;
+
+    fn poll_next(mut self: PinMut<Self>, _cx: &mut task::Context) -> Poll<Option<i32>> {
+        if !self.polled {
+            self.polled = true;
+            Poll::Pending
+        } else {
+            Poll::Ready(self.item.take())
+        }
+    }
+}
+
+#[test]
+fn poll_immediate_stream_maps_each_poll_outcome() {
+    let inner = PendingThenReady { polled: false, item: Some(42) };
+    let mut stream = inner.poll_immediate();
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Poll::Pending)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(Poll::Ready(42))));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    });
+}
+
+// A future that's `Pending` on its first poll, then `Ready(5)`.
+struct PendingOnce(bool);
+
+impl Future for PendingOnce {
+    type Output = i32;
+
+    fn poll(mut self: PinMut<Self>, _cx: &mut task::Context) -> Poll<i32> {
+        if !self.0 {
+            self.0 = true;
+            Poll::Pending
+        } else {
+            Poll::Ready(5)
+        }
+    }
+}
+
+#[test]
+fn poll_immediate_future_resolves_after_single_poll() {
+    support::with_noop_waker_context(|cx| {
+        let mut fut = PendingOnce(false).poll_immediate();
+        assert_eq!(fut.poll_unpin(cx), Poll::Ready(Poll::Pending));
+    });
+}
+
+#[test]
+fn poll_immediate_future_passes_through_ready() {
+    support::with_noop_waker_context(|cx| {
+        let mut fut = future::ready(7).poll_immediate();
+        assert_eq!(fut.poll_unpin(cx), Poll::Ready(Poll::Ready(7)));
+    });
+}
@@ -0,0 +1,92 @@
+#![feature(pin, arbitrary_self_types, futures_api)]
+
+#[macro_use]
+extern crate futures;
+
+use std::collections::VecDeque;
+use std::mem::PinMut;
+
+use futures::prelude::*;
+use futures::stream::{select_with_strategy, PollNext};
+use futures::task::{self, Poll};
+
+mod support;
+
+// A stream that's immediately `Ready` with each of `items` in turn, then
+// `Ready(None)` forever after.
+struct Seq(VecDeque<i32>);
+
+impl Seq {
+    fn new(items: Vec<i32>) -> Self {
+        Seq(items.into_iter().collect())
+    }
+}
+
+impl Stream for Seq {
+    type Item = i32;
+
+    fn poll_next(mut self: PinMut<Self>, _cx: &mut task::Context) -> Poll<Option<i32>> {
+        Poll::Ready(self.0.pop_front())
+    }
+}
+
+#[test]
+fn prefers_left_when_both_ready() {
+    let left = Seq::new(vec![1, 2]);
+    let right = Seq::new(vec![10, 20]);
+
+    let mut stream = select_with_strategy(left, right, (), |_: &mut ()| PollNext::Left);
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(2)));
+        // `left` is now exhausted; `right`'s items should still surface.
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(10)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(20)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    });
+}
+
+#[test]
+fn strict_alternation_via_toggle() {
+    let left = Seq::new(vec![1, 2, 3]);
+    let right = Seq::new(vec![10, 20, 30]);
+
+    let mut stream = select_with_strategy(
+        left,
+        right,
+        PollNext::Left,
+        |state: &mut PollNext| state.toggle(),
+    );
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(10)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(2)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(20)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(3)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(30)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    });
+}
+
+#[test]
+fn continues_past_exhaustion_of_one_side() {
+    let left = Seq::new(vec![1]);
+    let right = Seq::new(vec![10, 20]);
+
+    let mut stream = select_with_strategy(left, right, (), |_: &mut ()| PollNext::Left);
+
+    support::with_noop_waker_context(|cx| {
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(1)));
+        // `left` is now exhausted. A correct implementation must not
+        // re-poll it (that's what `Fuse` guards against) and must fall
+        // through to `right` instead of returning `Pending` or `None`
+        // prematurely.
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(10)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(Some(20)));
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+        // Only once both sides are exhausted does it stay `Ready(None)`.
+        assert_eq!(stream.poll_next_unpin(cx), Poll::Ready(None));
+    });
+}